@@ -194,7 +194,8 @@ impl UniversalArtifact {
             serializable.compilation.function_relocations.clone(),
             &custom_sections,
             &serializable.compilation.custom_section_relocations,
-        );
+        )
+        .map_err(|e| CompileError::Codegen(format!("failed to link the module: {}", e)))?;
 
         // Compute indices into the shared signature table.
         let signatures = {
@@ -5,18 +5,30 @@ use wasmer_compiler::{
     JumpTable, JumpTableOffsets, Relocation, RelocationKind, RelocationTarget, Relocations,
     SectionIndex,
 };
-use wasmer_engine::FunctionExtent;
+use wasmer_engine::{FunctionExtent, LinkError};
 use wasmer_types::entity::{EntityRef, PrimaryMap};
 use wasmer_types::{LocalFunctionIndex, ModuleInfo};
 use wasmer_vm::SectionBodyPtr;
 
+/// Checks whether the delta of a `X86CallPCRel4` relocation fits in the
+/// 32 bits it's encoded in.
+///
+/// `Relocation::for_address` truncates the delta to 32 bits, so the full
+/// delta is computed here.
+fn call_pcrel4_in_range(target: usize, reloc_address: usize, addend: i64) -> bool {
+    let delta = (target as i64)
+        .wrapping_sub(reloc_address as i64)
+        .wrapping_add(addend);
+    delta <= i32::MAX as i64 && delta >= i32::MIN as i64
+}
+
 fn apply_relocation(
     body: usize,
     r: &Relocation,
     allocated_functions: &PrimaryMap<LocalFunctionIndex, FunctionExtent>,
     jt_offsets: &PrimaryMap<LocalFunctionIndex, JumpTableOffsets>,
     allocated_sections: &PrimaryMap<SectionIndex, SectionBodyPtr>,
-) {
+) -> Result<(), LinkError> {
     let target_func_address: usize = match r.reloc_target {
         RelocationTarget::LocalFunc(index) => *allocated_functions[index].ptr as usize,
         RelocationTarget::LibCall(libcall) => libcall.function_pointer(),
@@ -48,16 +60,25 @@ fn apply_relocation(
             let (reloc_address, reloc_delta) = r.for_address(body, target_func_address as u64);
             write_unaligned(reloc_address as *mut u64, reloc_delta);
         },
-        RelocationKind::X86CallPCRel4 => unsafe {
+        RelocationKind::X86CallPCRel4 => {
             let (reloc_address, reloc_delta) = r.for_address(body, target_func_address as u64);
-            write_unaligned(reloc_address as *mut u32, reloc_delta as _);
-        },
+            if !call_pcrel4_in_range(target_func_address, reloc_address, r.addend) {
+                return Err(LinkError::RelocationOutOfRange(format!(
+                    "{} relocation at {:#x} to {:?} ({:#x})",
+                    r.kind, reloc_address, r.reloc_target, target_func_address
+                )));
+            }
+            unsafe {
+                write_unaligned(reloc_address as *mut u32, reloc_delta as _);
+            }
+        }
         RelocationKind::X86PCRelRodata4 => {}
         kind => panic!(
             "Relocation kind unsupported in the current architecture {}",
             kind
         ),
     }
+    Ok(())
 }
 
 /// Links a module, patching the allocated functions with the
@@ -69,17 +90,51 @@ pub fn link_module(
     function_relocations: Relocations,
     allocated_sections: &PrimaryMap<SectionIndex, SectionBodyPtr>,
     section_relocations: &PrimaryMap<SectionIndex, Vec<Relocation>>,
-) {
+) -> Result<(), LinkError> {
     for (i, section_relocs) in section_relocations.iter() {
         let body = *allocated_sections[i] as usize;
         for r in section_relocs {
-            apply_relocation(body, r, allocated_functions, jt_offsets, allocated_sections);
+            apply_relocation(body, r, allocated_functions, jt_offsets, allocated_sections)?;
         }
     }
     for (i, function_relocs) in function_relocations.iter() {
         let body = *allocated_functions[i].ptr as usize;
         for r in function_relocs {
-            apply_relocation(body, r, allocated_functions, jt_offsets, allocated_sections);
+            apply_relocation(body, r, allocated_functions, jt_offsets, allocated_sections)?;
         }
     }
+    Ok(())
+}
+
+#[cfg(all(test, target_pointer_width = "64"))]
+mod tests {
+    use super::call_pcrel4_in_range;
+
+    const BASE: usize = 0x1_0000_0000;
+
+    #[test]
+    fn call_pcrel4_forward_bounds() {
+        let max = i32::MAX as usize;
+        assert!(call_pcrel4_in_range(BASE + max, BASE, 0));
+        assert!(!call_pcrel4_in_range(BASE + max + 1, BASE, 0));
+    }
+
+    #[test]
+    fn call_pcrel4_backward_bounds() {
+        let min = (i32::MIN as i64).unsigned_abs() as usize;
+        assert!(call_pcrel4_in_range(BASE - min, BASE, 0));
+        assert!(!call_pcrel4_in_range(BASE - min - 1, BASE, 0));
+    }
+
+    #[test]
+    fn call_pcrel4_negative_addend() {
+        // Calls are usually emitted with an addend of -4, relative to the
+        // end of the 4 bytes of the relocation.
+        let max = i32::MAX as usize;
+        assert!(call_pcrel4_in_range(BASE + max + 4, BASE, -4));
+        assert!(!call_pcrel4_in_range(BASE + max + 5, BASE, -4));
+        let min = (i32::MIN as i64).unsigned_abs() as usize;
+        assert!(call_pcrel4_in_range(BASE - min + 4, BASE, -4));
+        assert!(!call_pcrel4_in_range(BASE - min + 3, BASE, -4));
+    }
 }
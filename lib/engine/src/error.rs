@@ -76,6 +76,10 @@ pub enum LinkError {
     /// Insufficient resources available for linking.
     #[error("Insufficient resources: {0}")]
     Resource(String),
+
+    /// A relocation target is too far away to be encoded in the relocation.
+    #[error("Relocation target is out of range: {0}")]
+    RelocationOutOfRange(String),
 }
 
 /// An error while instantiating a module.
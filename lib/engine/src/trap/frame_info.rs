@@ -75,8 +75,8 @@ impl ModuleInfoFrameInfo {
 
     /// Gets a function given a pc
     fn function_info(&self, pc: usize) -> Option<&FunctionInfo> {
-        let (end, func) = self.functions.range(pc..).next()?;
-        if func.start <= pc && pc <= *end {
+        let (_, func) = self.functions.range(pc..).next()?;
+        if func.extent.contains_pc(pc) {
             return Some(func);
         } else {
             None
@@ -86,10 +86,16 @@ impl ModuleInfoFrameInfo {
 
 #[derive(Debug)]
 struct FunctionInfo {
-    start: usize,
+    extent: FunctionExtent,
     local_index: LocalFunctionIndex,
 }
 
+impl FunctionInfo {
+    fn start(&self) -> usize {
+        *self.extent.ptr as usize
+    }
+}
+
 impl GlobalFrameInfo {
     /// Fetches frame information about a program counter in a backtrace.
     ///
@@ -102,7 +108,7 @@ impl GlobalFrameInfo {
         // Use our relative position from the start of the function to find the
         // machine instruction that corresponds to `pc`, which then allows us to
         // map that to a wasm original source location.
-        let rel_pos = pc - func.start;
+        let rel_pos = pc - func.start();
         let instr_map = &module.function_debug_info(func.local_index).address_map;
         let pos = match instr_map
             .instructions
@@ -154,7 +160,7 @@ impl GlobalFrameInfo {
         let func = module.function_info(pc)?;
         let traps = &module.function_debug_info(func.local_index).traps;
         let idx = traps
-            .binary_search_by_key(&((pc - func.start) as u32), |info| info.code_offset)
+            .binary_search_by_key(&((pc - func.start()) as u32), |info| info.code_offset)
             .ok()?;
         Some(&traps[idx])
     }
@@ -190,6 +196,29 @@ pub struct FunctionExtent {
     pub length: usize,
 }
 
+impl FunctionExtent {
+    /// Returns whether `pc` lies within the function body, or right after it.
+    ///
+    /// The end is inclusive, as needed by the frame info lookups: a return
+    /// address can point right after the last instruction of the function.
+    /// This means that for two adjacent functions `a` and `b`, both
+    /// `a.contains_pc(pc)` and `b.contains_pc(pc)` are true when `pc` is the
+    /// start of `b`.
+    pub fn contains_pc(&self, pc: usize) -> bool {
+        let start = *self.ptr as usize;
+        start <= pc && pc - start <= self.length
+    }
+
+    /// Returns the compiled bytes of the function body.
+    ///
+    /// # Safety
+    /// The executable memory this extent points to must still be allocated
+    /// for the lifetime of the returned slice.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        std::slice::from_raw_parts(*self.ptr as *const u8, self.length)
+    }
+}
+
 /// Registers a new compiled module's frame information.
 ///
 /// This function will register the `names` information for all of the
@@ -204,20 +233,16 @@ pub fn register(
     let mut min = usize::max_value();
     let mut max = 0;
     let mut functions = BTreeMap::new();
-    for (
-        i,
-        FunctionExtent {
-            ptr: start,
-            length: len,
-        },
-    ) in finished_functions.iter()
-    {
-        let start = **start as usize;
-        let end = start + len;
+    for (i, extent) in finished_functions.iter() {
+        let start = *extent.ptr as usize;
+        let end = start + extent.length;
         min = cmp::min(min, start);
         max = cmp::max(max, end);
         let func = FunctionInfo {
-            start,
+            extent: FunctionExtent {
+                ptr: extent.ptr,
+                length: extent.length,
+            },
             local_index: i,
         };
         assert!(functions.insert(end, func).is_none());
@@ -326,3 +351,34 @@ impl FrameInfo {
         (self.instr.bits() - self.func_start.bits()) as usize
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FunctionExtent;
+    use wasmer_vm::{FunctionBodyPtr, VMFunctionBody};
+
+    #[test]
+    fn function_extent_contains_pc() {
+        let start = 0x1000;
+        let length = 0x20;
+        let extent = FunctionExtent {
+            ptr: FunctionBodyPtr(start as *const VMFunctionBody),
+            length,
+        };
+        assert!(!extent.contains_pc(start - 1));
+        assert!(extent.contains_pc(start));
+        assert!(extent.contains_pc(start + length - 1));
+        assert!(extent.contains_pc(start + length));
+        assert!(!extent.contains_pc(start + length + 1));
+    }
+
+    #[test]
+    fn function_extent_as_slice() {
+        let body: [u8; 8] = [0x55, 0x48, 0x89, 0xe5, 0x5d, 0xc3, 0xcc, 0xcc];
+        let extent = FunctionExtent {
+            ptr: FunctionBodyPtr(body.as_ptr() as *const VMFunctionBody),
+            length: 6,
+        };
+        assert_eq!(unsafe { extent.as_slice() }, &body[..6]);
+    }
+}
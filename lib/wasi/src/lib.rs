@@ -22,8 +22,8 @@ mod utils;
 use crate::syscalls::*;
 
 pub use crate::state::{
-    Fd, Pipe, Stderr, Stdin, Stdout, WasiFile, WasiFs, WasiFsError, WasiState, WasiStateBuilder,
-    WasiStateCreationError, ALL_RIGHTS, VIRTUAL_ROOT_FD,
+    Fd, Pipe, RealClock, SimulatedClock, Stderr, Stdin, Stdout, WasiClock, WasiFile, WasiFs,
+    WasiFsError, WasiState, WasiStateBuilder, WasiStateCreationError, ALL_RIGHTS, VIRTUAL_ROOT_FD,
 };
 pub use crate::syscalls::types;
 pub use crate::utils::{get_wasi_version, get_wasi_versions, is_wasi_module, WasiVersion};
@@ -56,6 +56,9 @@ pub struct WasiEnv {
     /// if the lock is held and the Wasm calls into a host function that tries
     /// to lock this mutex, the program will deadlock.
     pub state: Arc<Mutex<WasiState>>,
+    /// The clock used by `clock_time_get` and `clock_res_get` for the
+    /// realtime and monotonic clocks.
+    clock: Arc<dyn WasiClock>,
     #[wasmer(export)]
     memory: LazyInit<Memory>,
 }
@@ -64,10 +67,32 @@ impl WasiEnv {
     pub fn new(state: WasiState) -> Self {
         Self {
             state: Arc::new(Mutex::new(state)),
+            clock: Arc::new(RealClock),
             memory: LazyInit::new(),
         }
     }
 
+    /// Replace the clock used for the realtime and monotonic clocks read by
+    /// `clock_time_get` and `clock_res_get`, [`RealClock`] by default.
+    ///
+    /// Use a [`SimulatedClock`] to get deterministic time, e.g. in tests.
+    /// Like the host clocks, the `precision` requested by `clock_time_get`
+    /// is ignored. The resolution reported by `clock_res_get` comes from
+    /// [`WasiClock::realtime_resolution`] and
+    /// [`WasiClock::monotonic_resolution`].
+    ///
+    /// Note that `poll_oneoff` clock subscriptions don't use this clock: a
+    /// WASI program that sleeps will still block for the requested host
+    /// time, and a [`SimulatedClock`] won't advance while it does.
+    pub fn set_runtime_clock(&mut self, clock: Arc<dyn WasiClock>) {
+        self.clock = clock;
+    }
+
+    /// Get the clock used for the realtime and monotonic clocks.
+    pub fn clock(&self) -> &dyn WasiClock {
+        &*self.clock
+    }
+
     /// Get an `ImportObject` for a specific version of WASI detected in the module.
     pub fn import_object(&mut self, module: &Module) -> Result<ImportObject, WasiError> {
         let wasi_version = get_wasi_version(module, false).ok_or(WasiError::UnknownWasiVersion)?;
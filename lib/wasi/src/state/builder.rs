@@ -1,9 +1,10 @@
 //! Builder system for configuring a [`WasiState`] and creating it.
 
-use crate::state::{WasiFile, WasiFs, WasiFsError, WasiState};
+use crate::state::{WasiClock, WasiFile, WasiFs, WasiFsError, WasiState};
 use crate::syscalls::types::{__WASI_STDERR_FILENO, __WASI_STDIN_FILENO, __WASI_STDOUT_FILENO};
 use crate::WasiEnv;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use thiserror::Error;
 
 /// Creates an empty [`WasiStateBuilder`].
@@ -42,6 +43,7 @@ pub struct WasiStateBuilder {
     stdout_override: Option<Box<dyn WasiFile>>,
     stderr_override: Option<Box<dyn WasiFile>>,
     stdin_override: Option<Box<dyn WasiFile>>,
+    clock: Option<Arc<dyn WasiClock>>,
 }
 
 impl std::fmt::Debug for WasiStateBuilder {
@@ -54,6 +56,7 @@ impl std::fmt::Debug for WasiStateBuilder {
             .field("stdout_override exists", &self.stdout_override.is_some())
             .field("stderr_override exists", &self.stderr_override.is_some())
             .field("stdin_override exists", &self.stdin_override.is_some())
+            .field("clock exists", &self.clock.is_some())
             .finish()
     }
 }
@@ -282,6 +285,20 @@ impl WasiStateBuilder {
         self
     }
 
+    /// Overwrite the default clock used for the realtime and monotonic
+    /// clocks, for example with a [`SimulatedClock`] to get deterministic
+    /// time in tests.
+    ///
+    /// The clock is only applied by [`WasiStateBuilder::finalize`], see
+    /// [`WasiEnv::set_runtime_clock`] for its limitations.
+    ///
+    /// [`SimulatedClock`]: crate::SimulatedClock
+    pub fn with_clock(&mut self, clock: impl WasiClock) -> &mut Self {
+        self.clock = Some(Arc::new(clock));
+
+        self
+    }
+
     /// Setup the WASI filesystem before running
     // TODO: improve ergonomics on this function
     pub fn setup_fs(
@@ -405,7 +422,11 @@ impl WasiStateBuilder {
     /// Returns the error from `WasiFs::new` if there's an error
     pub fn finalize(&mut self) -> Result<WasiEnv, WasiStateCreationError> {
         let state = self.build()?;
-        Ok(WasiEnv::new(state))
+        let mut env = WasiEnv::new(state);
+        if let Some(clock) = self.clock.clone() {
+            env.set_runtime_clock(clock);
+        }
+        Ok(env)
     }
 }
 
@@ -555,6 +576,25 @@ mod test {
         );
     }
 
+    #[test]
+    fn simulated_clock_is_used_by_env() {
+        use crate::state::SimulatedClock;
+        use std::time::Duration;
+
+        let clock = SimulatedClock::new(Duration::from_secs(1));
+        let env = create_wasi_state("test_prog")
+            .with_clock(clock.clone())
+            .finalize()
+            .unwrap();
+        let env_clock = env.clock();
+        assert_eq!(env_clock.realtime(), Duration::from_secs(1));
+        assert_eq!(env_clock.monotonic(), Duration::from_secs(1));
+
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(env_clock.realtime(), Duration::from_millis(1_500));
+        assert_eq!(env_clock.monotonic(), Duration::from_millis(1_500));
+    }
+
     #[test]
     fn nul_character_in_args() {
         let output = create_wasi_state("test_prog").arg("--h\0elp").build();
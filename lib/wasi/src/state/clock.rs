@@ -0,0 +1,140 @@
+//! Clock sources used by the WASI `clock_time_get` and `clock_res_get`
+//! syscalls.
+//!
+//! By default the host clocks are used, see [`RealClock`].  Use a
+//! [`SimulatedClock`] to control the time seen by the WASI program, for
+//! example to get deterministic output in tests.
+
+use crate::syscalls::types::*;
+use crate::syscalls::{platform_clock_res_get, platform_clock_time_get};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::debug;
+use wasmer::WasmCell;
+
+/// A source of time for the WASI realtime and monotonic clocks.
+pub trait WasiClock: fmt::Debug + Send + Sync + 'static {
+    /// The time elapsed since the Unix epoch.
+    fn realtime(&self) -> Duration;
+
+    /// The time elapsed since an arbitrary, fixed point in the past.
+    ///
+    /// The returned value must never decrease.
+    fn monotonic(&self) -> Duration;
+
+    /// The resolution of the realtime clock, 1ns by default.
+    fn realtime_resolution(&self) -> Duration {
+        Duration::from_nanos(1)
+    }
+
+    /// The resolution of the monotonic clock, 1ns by default.
+    fn monotonic_resolution(&self) -> Duration {
+        Duration::from_nanos(1)
+    }
+}
+
+/// The default [`WasiClock`], reading the host realtime and monotonic
+/// clocks.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealClock;
+
+/// Reads a host clock value through one of the `platform_clock_*` functions.
+fn read_host_clock(read: impl FnOnce(WasmCell<__wasi_timestamp_t>) -> __wasi_errno_t) -> Duration {
+    let nanos = Cell::new(0);
+    let result = read(WasmCell::new(&nanos));
+    if result != __WASI_ESUCCESS {
+        debug!("Error reading the host clock: {}", result);
+    }
+    Duration::from_nanos(nanos.get())
+}
+
+impl WasiClock for RealClock {
+    fn realtime(&self) -> Duration {
+        read_host_clock(|time| platform_clock_time_get(__WASI_CLOCK_REALTIME, 0, time))
+    }
+
+    fn monotonic(&self) -> Duration {
+        read_host_clock(|time| platform_clock_time_get(__WASI_CLOCK_MONOTONIC, 0, time))
+    }
+
+    fn realtime_resolution(&self) -> Duration {
+        read_host_clock(|resolution| platform_clock_res_get(__WASI_CLOCK_REALTIME, resolution))
+    }
+
+    fn monotonic_resolution(&self) -> Duration {
+        read_host_clock(|resolution| platform_clock_res_get(__WASI_CLOCK_MONOTONIC, resolution))
+    }
+}
+
+/// A [`WasiClock`] whose time only changes when [`SimulatedClock::advance`]
+/// is called.
+///
+/// Clones share the same time, so a clone can be kept to advance the clock
+/// after it has been given to the WASI environment.
+///
+/// Usage:
+/// ```no_run
+/// # use std::time::Duration;
+/// # use wasmer_wasi::{SimulatedClock, WasiState, WasiStateCreationError};
+/// # fn main() -> Result<(), WasiStateCreationError> {
+/// let clock = SimulatedClock::new(Duration::from_secs(0));
+/// let wasi_env = WasiState::new("wasi-prog-name")
+///    .with_clock(clock.clone())
+///    .finalize()?;
+/// clock.advance(Duration::from_millis(10));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedClock {
+    time: Arc<Mutex<Duration>>,
+}
+
+impl SimulatedClock {
+    /// Create a new `SimulatedClock` starting at `time`.
+    pub fn new(time: Duration) -> Self {
+        Self {
+            time: Arc::new(Mutex::new(time)),
+        }
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let mut time = self.time.lock().unwrap();
+        *time += duration;
+    }
+
+    /// Get the current simulated time.
+    pub fn time(&self) -> Duration {
+        *self.time.lock().unwrap()
+    }
+}
+
+impl WasiClock for SimulatedClock {
+    fn realtime(&self) -> Duration {
+        self.time()
+    }
+
+    fn monotonic(&self) -> Duration {
+        self.time()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn simulated_clock_advances_only_when_asked() {
+        let clock = SimulatedClock::new(Duration::from_secs(5));
+        let handle = clock.clone();
+        assert_eq!(clock.realtime(), Duration::from_secs(5));
+        assert_eq!(clock.monotonic(), Duration::from_secs(5));
+
+        handle.advance(Duration::from_millis(250));
+        assert_eq!(clock.realtime(), Duration::from_millis(5_250));
+        assert_eq!(clock.monotonic(), Duration::from_millis(5_250));
+    }
+}
@@ -16,9 +16,11 @@
 #![allow(clippy::cognitive_complexity, clippy::too_many_arguments)]
 
 mod builder;
+mod clock;
 mod types;
 
 pub use self::builder::*;
+pub use self::clock::*;
 pub use self::types::*;
 use crate::syscalls::types::*;
 use generational_arena::Arena;
@@ -221,7 +221,17 @@ pub fn clock_res_get(
     let memory = env.memory();
 
     let out_addr = wasi_try!(resolution.deref(memory));
-    platform_clock_res_get(clock_id, out_addr)
+    match clock_id {
+        __WASI_CLOCK_REALTIME => {
+            out_addr.set(env.clock().realtime_resolution().as_nanos() as __wasi_timestamp_t);
+            __WASI_ESUCCESS
+        }
+        __WASI_CLOCK_MONOTONIC => {
+            out_addr.set(env.clock().monotonic_resolution().as_nanos() as __wasi_timestamp_t);
+            __WASI_ESUCCESS
+        }
+        _ => platform_clock_res_get(clock_id, out_addr),
+    }
 }
 
 /// ### `clock_time_get()`
@@ -247,7 +257,17 @@ pub fn clock_time_get(
     let memory = env.memory();
 
     let out_addr = wasi_try!(time.deref(memory));
-    let result = platform_clock_time_get(clock_id, precision, out_addr);
+    let result = match clock_id {
+        __WASI_CLOCK_REALTIME => {
+            out_addr.set(env.clock().realtime().as_nanos() as __wasi_timestamp_t);
+            __WASI_ESUCCESS
+        }
+        __WASI_CLOCK_MONOTONIC => {
+            out_addr.set(env.clock().monotonic().as_nanos() as __wasi_timestamp_t);
+            __WASI_ESUCCESS
+        }
+        _ => platform_clock_time_get(clock_id, precision, out_addr),
+    };
     debug!(
         "time: {} => {}",
         wasi_try!(time.deref(memory)).get(),
@@ -2584,3 +2604,59 @@ pub fn sock_shutdown(env: &WasiEnv, sock: __wasi_fd_t, how: __wasi_sdflags_t) ->
     debug!("wasi::sock_shutdown");
     unimplemented!("wasi::sock_shutdown")
 }
+
+#[cfg(test)]
+mod test {
+    use super::types::*;
+    use crate::{SimulatedClock, WasiState};
+    use std::time::Duration;
+    use wasmer::{Instance, Module, NativeFunc, Store};
+
+    const CLOCK_WAT: &str = r#"
+        (module
+          (import "wasi_snapshot_preview1" "clock_time_get"
+            (func $clock_time_get (param i32 i64 i32) (result i32)))
+          (import "wasi_snapshot_preview1" "clock_res_get"
+            (func $clock_res_get (param i32 i32) (result i32)))
+          (memory (export "memory") 1)
+          (func (export "time") (param $id i32) (result i64)
+            (drop (call $clock_time_get (local.get $id) (i64.const 0) (i32.const 0)))
+            (i64.load (i32.const 0)))
+          (func (export "res") (param $id i32) (result i64)
+            (drop (call $clock_res_get (local.get $id) (i32.const 0)))
+            (i64.load (i32.const 0))))
+    "#;
+
+    #[test]
+    fn clock_syscalls_use_simulated_clock() {
+        let store = Store::default();
+        let module = Module::new(&store, CLOCK_WAT).unwrap();
+        let clock = SimulatedClock::new(Duration::from_secs(1));
+        let mut wasi_env = WasiState::new("test_prog")
+            .with_clock(clock.clone())
+            .finalize()
+            .unwrap();
+        let import_object = wasi_env.import_object(&module).unwrap();
+        let instance = Instance::new(&module, &import_object).unwrap();
+        let time: NativeFunc<u32, u64> = instance.exports.get_native_function("time").unwrap();
+        let res: NativeFunc<u32, u64> = instance.exports.get_native_function("res").unwrap();
+
+        assert_eq!(time.call(__WASI_CLOCK_REALTIME).unwrap(), 1_000_000_000);
+        assert_eq!(time.call(__WASI_CLOCK_MONOTONIC).unwrap(), 1_000_000_000);
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(time.call(__WASI_CLOCK_REALTIME).unwrap(), 1_250_000_000);
+        assert_eq!(time.call(__WASI_CLOCK_MONOTONIC).unwrap(), 1_250_000_000);
+
+        assert_eq!(res.call(__WASI_CLOCK_REALTIME).unwrap(), 1);
+        assert_eq!(res.call(__WASI_CLOCK_MONOTONIC).unwrap(), 1);
+
+        // Other clocks still read the host clocks.
+        #[cfg(not(target_os = "windows"))]
+        {
+            let cputime = time.call(__WASI_CLOCK_PROCESS_CPUTIME_ID).unwrap();
+            assert_ne!(cputime, 0);
+            assert_ne!(cputime, 1_250_000_000);
+        }
+    }
+}